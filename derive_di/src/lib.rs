@@ -193,6 +193,251 @@
 //! }
 //! ```
 //!
+//! ### Singletons
+//! By default every injected field gets its own instance. Mark a service as `singleton` to
+//! share a single `Arc`-wrapped instance across every container that injects it.
+//! ```rust
+//! use derive_di::*;
+//! use std::sync::Arc;
+//!
+//! #[injectable(singleton, factory => InjectableStruct {inner: "test".to_owned()})]
+//! struct InjectableStruct {
+//!     inner: String,
+//! }
+//!
+//! #[derive(Container)]
+//! struct MyContainer {
+//!     #[inject(InjectableStruct)]
+//!     i_struct: Arc<InjectableStruct>,
+//! }
+//! ```
+//! The `Default` impl of the `MyContainer` will be looks like
+//!
+//! ```rust
+//! use derive_di::*;
+//! use std::sync::Arc;
+//!
+//! #[injectable(singleton, factory => InjectableStruct {inner: "test".to_owned()})]
+//! struct InjectableStruct {
+//!     inner: String,
+//! }
+//!
+//! struct MyContainer {
+//!     i_struct: Arc<InjectableStruct>,
+//! }
+//! impl Default for MyContainer {
+//!     fn default() -> Self {
+//!         Self {
+//!             i_struct: Arc::clone(&InjectableStruct::get_singleton())
+//!         }
+//!     }
+//! }
+//! ```
+//! ### Named providers
+//! A single concrete type can register more than one named provider, so different container
+//! fields can each get a differently configured instance of the same underlying struct.
+//! ```rust
+//! use derive_di::*;
+//!
+//! #[injectable(name = "primary", factory => InjectableStruct {inner: "primary".to_owned()})]
+//! #[injectable(name = "secondary", factory => InjectableStruct {inner: "secondary".to_owned()})]
+//! struct InjectableStruct {
+//!     inner: String,
+//! }
+//!
+//! trait Getter {
+//!     fn get(&self) -> String;
+//! }
+//!
+//! impl Getter for InjectableStruct {
+//!     fn get(&self) -> String {
+//!         self.inner.clone()
+//!     }
+//! }
+//!
+//! #[derive(Container)]
+//! struct MyContainer {
+//!     #[inject(InjectableStruct as primary)]
+//!     primary: Box<dyn Getter>,
+//!     #[inject(InjectableStruct as secondary)]
+//!     secondary: Box<dyn Getter>,
+//! }
+//! ```
+//! The `Default` impl of the `MyContainer` calls each named provider directly:
+//!
+//! ```rust
+//! use derive_di::*;
+//!
+//! #[injectable(name = "primary", factory => InjectableStruct {inner: "primary".to_owned()})]
+//! #[injectable(name = "secondary", factory => InjectableStruct {inner: "secondary".to_owned()})]
+//! struct InjectableStruct {
+//!     inner: String,
+//! }
+//!
+//! trait Getter {
+//!     fn get(&self) -> String;
+//! }
+//!
+//! impl Getter for InjectableStruct {
+//!     fn get(&self) -> String {
+//!         self.inner.clone()
+//!     }
+//! }
+//!
+//! struct MyContainer {
+//!     primary: Box<dyn Getter>,
+//!     secondary: Box<dyn Getter>,
+//! }
+//! impl Default for MyContainer {
+//!     fn default() -> Self {
+//!         Self {
+//!             primary: Box::from(InjectableStruct::get_service_primary()),
+//!             secondary: Box::from(InjectableStruct::get_service_secondary()),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ### Circular dependency detection
+//! A `Container` also gets a fallible `try_default`, which resolves every plain and
+//! `#[inject(Origin)]` field through `Injectable::get_service_tracked` instead of `get_service`.
+//! It threads a resolution history through each of those fields, so a cycle comes back as
+//! `Err(InjectError::Circular { .. })` with the full chain of type names instead of overflowing
+//! the stack. Singleton, named-provider, `#[no_inject]` and `#[default(expr)]` fields are built
+//! the same way as in `default()`, without tracking.
+//! ```rust
+//! use derive_di::*;
+//!
+//! #[injectable(factory => InjectableStruct)]
+//! struct InjectableStruct;
+//!
+//! #[derive(Container)]
+//! struct MyContainer {
+//!     i_struct: InjectableStruct,
+//! }
+//!
+//! assert!(MyContainer::try_default().is_ok());
+//! ```
+//! ### Async construction
+//! Some services need to `.await` while they're built, e.g. opening a network connection.
+//! Mark the factory `is_async` and annotate the corresponding `Container` field with
+//! `#[async_inject]`; `default_async` will `.await` it while still building every other field
+//! through the regular synchronous path.
+//! ```rust
+//! use derive_di::*;
+//!
+//! #[injectable(is_async, factory => async { InjectableStruct {inner: "test".to_owned()} }.await)]
+//! struct InjectableStruct {
+//!     inner: String,
+//! }
+//!
+//! #[derive(Container)]
+//! struct MyContainer {
+//!     #[async_inject]
+//!     i_struct: InjectableStruct,
+//! }
+//! ```
+//! The `default_async` of the `MyContainer` will be looks like
+//!
+//! ```rust
+//! use derive_di::*;
+//!
+//! #[injectable(is_async, factory => async { InjectableStruct {inner: "test".to_owned()} }.await)]
+//! struct InjectableStruct {
+//!     inner: String,
+//! }
+//!
+//! struct MyContainer {
+//!     i_struct: InjectableStruct,
+//! }
+//! impl MyContainer {
+//!     pub async fn default_async() -> Self {
+//!         Self {
+//!             i_struct: AsyncInjectable::get_service_async().await
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ### Opting a field out of injection
+//! Not every field holds an `Injectable` service. `#[no_inject]` falls back to
+//! `Default::default()`, and `#[default(expr)]` uses a literal expression instead.
+//! ```rust
+//! use derive_di::*;
+//!
+//! #[derive(Container)]
+//! struct MyContainer {
+//!     #[no_inject]
+//!     counter: u32,
+//!     #[default("v1".to_owned())]
+//!     version: String,
+//! }
+//! ```
+//! The `Default` impl of the `MyContainer` will be looks like
+//!
+//! ```rust
+//! struct MyContainer {
+//!     counter: u32,
+//!     version: String,
+//! }
+//! impl Default for MyContainer {
+//!     fn default() -> Self {
+//!         Self {
+//!             counter: Default::default(),
+//!             version: "v1".to_owned(),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ### Constructor injection
+//! You can also put `injectable` on an `impl` block with a `new` method instead of on the
+//! struct itself. Every argument of `new` is resolved the same way a `Container` field would be,
+//! so `get_service()` becomes a call to `new` with every dependency filled in.
+//! ```rust
+//! use derive_di::*;
+//!
+//! #[injectable(factory => InjectableStruct)]
+//! struct InjectableStruct;
+//!
+//! struct Foo {
+//!     i_struct: InjectableStruct,
+//! }
+//!
+//! #[injectable]
+//! impl Foo {
+//!     fn new(i_struct: InjectableStruct) -> Self {
+//!         Foo { i_struct }
+//!     }
+//! }
+//! ```
+//! The `Injectable` impl of `Foo` will be looks like
+//!
+//! ```rust
+//! use derive_di::*;
+//!
+//! #[injectable(factory => InjectableStruct)]
+//! struct InjectableStruct;
+//!
+//! struct Foo {
+//!     i_struct: InjectableStruct,
+//! }
+//!
+//! impl Foo {
+//!     fn new(i_struct: InjectableStruct) -> Self {
+//!         Foo { i_struct }
+//!     }
+//! }
+//!
+//! impl Injectable for Foo {
+//!     fn get_service() -> Self {
+//!         Foo::new(Injectable::get_service())
+//!     }
+//! }
+//! ```
+//! A `Box<dyn Trait>` argument can be bridged from a concrete type with the same
+//! `#[inject(Concrete)]` attribute used on `Container` fields.
+//!
 //! ### Mocks
 //! You can combine the `dyn Trait` fields and setters in your container
 //! and mock any logic for simple testing.
@@ -236,12 +481,13 @@
 
 extern crate derive_di_macro;
 
-pub use derive_di_core::injectable::Injectable;
+pub use derive_di_core::injectable::{AsyncInjectable, InjectError, Injectable, SingletonInjectable};
 pub use derive_di_macro::{injectable, Container};
 
 #[cfg(test)]
 mod tests {
-    use crate::{injectable, Container, Injectable};
+    use crate::{injectable, AsyncInjectable, Container, InjectError, Injectable, SingletonInjectable};
+    use std::sync::Arc;
 
     #[test]
     fn injectable_default_test() {
@@ -388,6 +634,231 @@ mod tests {
         assert_eq!("mocked", container.get_i_struct().get())
     }
 
+    #[test]
+    fn injectable_test_singleton() {
+        #[injectable(singleton, factory => InjectableStruct {inner: "test".to_owned()})]
+        struct InjectableStruct {
+            inner: String,
+        }
+
+        impl InjectableStruct {
+            fn get(&self) -> String {
+                self.inner.clone()
+            }
+        }
+
+        #[derive(Container)]
+        struct MyContainer {
+            #[inject(InjectableStruct)]
+            i_struct: Arc<InjectableStruct>,
+        }
+
+        let first = Arc::clone(MyContainer::default().get_i_struct());
+        let second = Arc::clone(MyContainer::default().get_i_struct());
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!("test", first.get());
+    }
+
+    #[test]
+    fn injectable_test_try_default() {
+        #[injectable(factory => InjectableStruct {inner: "test".to_owned()})]
+        struct InjectableStruct {
+            inner: String,
+        }
+
+        #[derive(Container)]
+        struct MyContainer {
+            i_struct: InjectableStruct,
+        }
+
+        let container = MyContainer::try_default().expect("no cycle, should resolve");
+
+        assert_eq!("test", container.get_i_struct().inner)
+    }
+
+    #[test]
+    fn injectable_test_get_service_tracked_circular() {
+        #[injectable(factory => InjectableStruct)]
+        struct InjectableStruct;
+
+        let type_name = std::any::type_name::<InjectableStruct>();
+        let mut history = vec![type_name];
+
+        let err = InjectableStruct::get_service_tracked(&mut history)
+            .err()
+            .expect("revisiting a type already in history must fail");
+
+        assert_eq!(
+            InjectError::Circular {
+                chain: vec![type_name, type_name]
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn injectable_test_constructor() {
+        #[injectable(factory => InjectableStruct {inner: "test".to_owned()})]
+        struct InjectableStruct {
+            inner: String,
+        }
+
+        struct Foo {
+            i_struct: InjectableStruct,
+        }
+
+        #[injectable]
+        impl Foo {
+            fn new(i_struct: InjectableStruct) -> Self {
+                Foo { i_struct }
+            }
+        }
+
+        assert_eq!("test", Foo::get_service().i_struct.inner)
+    }
+
+    #[test]
+    fn injectable_test_constructor_box() {
+        #[injectable(factory => InjectableStruct)]
+        struct InjectableStruct;
+
+        trait Getter {
+            fn get(&self) -> String;
+        }
+
+        impl Getter for InjectableStruct {
+            fn get(&self) -> String {
+                "test".to_owned()
+            }
+        }
+
+        struct Foo {
+            getter: Box<dyn Getter>,
+        }
+
+        #[injectable]
+        impl Foo {
+            fn new(#[inject(InjectableStruct)] getter: Box<dyn Getter>) -> Self {
+                Foo { getter }
+            }
+        }
+
+        assert_eq!("test", Foo::get_service().getter.get())
+    }
+
+    #[test]
+    fn injectable_test_constructor_circular() {
+        struct Foo {
+            #[allow(dead_code)]
+            bar: Box<Bar>,
+        }
+
+        struct Bar {
+            #[allow(dead_code)]
+            foo: Box<Foo>,
+        }
+
+        #[injectable]
+        impl Foo {
+            fn new(#[inject(Bar)] bar: Box<Bar>) -> Self {
+                Foo { bar }
+            }
+        }
+
+        #[injectable]
+        impl Bar {
+            fn new(#[inject(Foo)] foo: Box<Foo>) -> Self {
+                Bar { foo }
+            }
+        }
+
+        #[derive(Container)]
+        struct MyContainer {
+            foo: Foo,
+        }
+
+        let err = MyContainer::try_default()
+            .err()
+            .expect("mutual constructor injection must be detected as a cycle");
+
+        let foo_name = std::any::type_name::<Foo>();
+        let bar_name = std::any::type_name::<Bar>();
+
+        assert_eq!(
+            InjectError::Circular {
+                chain: vec![foo_name, bar_name, foo_name]
+            },
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn injectable_test_async() {
+        #[injectable(is_async, factory => async { InjectableStruct {inner: "test".to_owned()} }.await)]
+        struct InjectableStruct {
+            inner: String,
+        }
+
+        #[derive(Container)]
+        struct MyContainer {
+            #[async_inject]
+            i_struct: InjectableStruct,
+        }
+
+        let container = MyContainer::default_async().await;
+
+        assert_eq!("test", container.get_i_struct().inner)
+    }
+
+    #[test]
+    fn injectable_test_no_inject_and_default() {
+        #[derive(Container)]
+        struct MyContainer {
+            #[no_inject]
+            counter: u32,
+            #[default("v1".to_owned())]
+            version: String,
+        }
+
+        let container = MyContainer::default();
+
+        assert_eq!(0, *container.get_counter());
+        assert_eq!("v1", container.get_version());
+    }
+
+    #[test]
+    fn injectable_test_named_providers() {
+        #[injectable(name = "primary", factory => InjectableStruct {inner: "primary".to_owned()})]
+        #[injectable(name = "secondary", factory => InjectableStruct {inner: "secondary".to_owned()})]
+        struct InjectableStruct {
+            inner: String,
+        }
+
+        trait Getter {
+            fn get(&self) -> String;
+        }
+
+        impl Getter for InjectableStruct {
+            fn get(&self) -> String {
+                self.inner.clone()
+            }
+        }
+
+        #[derive(Container)]
+        struct MyContainer {
+            #[inject(InjectableStruct as primary)]
+            primary: Box<dyn Getter>,
+            #[inject(InjectableStruct as secondary)]
+            secondary: Box<dyn Getter>,
+        }
+
+        let container = MyContainer::default();
+
+        assert_eq!("primary", container.get_primary().get());
+        assert_eq!("secondary", container.get_secondary().get());
+    }
+
     #[test]
     fn injectable_test_inject_self() {
         #[injectable(factory => InjectableStruct)]
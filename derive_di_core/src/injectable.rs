@@ -1,4 +1,58 @@
 /// Injectable trait which used for `Default` trait implementation of derive `Container` macro.
 pub trait Injectable: Sized {
     fn get_service() -> Self;
+
+    /// Same as `get_service`, but threads a resolution `history` through the call so a cycle
+    /// (this type already being under construction somewhere up the chain) is reported as
+    /// `InjectError::Circular` instead of recursing until the stack overflows.
+    fn get_service_tracked(history: &mut Vec<&'static str>) -> Result<Self, InjectError> {
+        let type_name = std::any::type_name::<Self>();
+
+        if history.contains(&type_name) {
+            let mut chain = history.clone();
+            chain.push(type_name);
+            return Err(InjectError::Circular { chain });
+        }
+
+        history.push(type_name);
+        let service = Self::get_service();
+        history.pop();
+
+        Ok(service)
+    }
+}
+
+/// Errors produced while resolving an `Injectable` through its tracked resolution path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InjectError {
+    /// The type at the end of `chain` was already being resolved earlier in the same chain.
+    Circular { chain: Vec<&'static str> },
+}
+
+impl std::fmt::Display for InjectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InjectError::Circular { chain } => {
+                write!(f, "circular dependency detected: {}", chain.join(" -> "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for InjectError {}
+
+/// Injectable trait for services which should live for the whole process as a single shared
+/// instance, used for `Default` trait implementation of derive `Container` macro.
+///
+/// Unlike `Injectable`, a type implementing `SingletonInjectable` is constructed at most once:
+/// the first caller pays for the factory, every other caller gets a clone of the same `Arc`.
+pub trait SingletonInjectable: Sized {
+    fn get_singleton() -> std::sync::Arc<Self>;
+}
+
+/// Injectable trait for services whose construction needs to `.await`, e.g. opening a network
+/// connection, used for the `async fn default_async()` companion of derive `Container`.
+#[async_trait::async_trait]
+pub trait AsyncInjectable: Sized {
+    async fn get_service_async() -> Self;
 }
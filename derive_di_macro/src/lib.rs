@@ -5,10 +5,10 @@ use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::{parse_macro_input, Data, DeriveInput, Expr, PathSegment, Token, Type};
+use syn::{parse_macro_input, Data, DeriveInput, Expr, FnArg, ItemImpl, PathSegment, Token, Type};
 
 /// Derive `Container` macro, will be implement getters, setters and `Default` trait for the struct.
-#[proc_macro_derive(Container, attributes(inject))]
+#[proc_macro_derive(Container, attributes(inject, no_inject, default, async_inject))]
 pub fn derive_container_fn(input: TokenStream) -> TokenStream {
     let derived_container = parse_macro_input!(input as DeriveInput);
     let name = &derived_container.ident;
@@ -46,19 +46,44 @@ pub fn derive_container_fn(input: TokenStream) -> TokenStream {
     let new_constructor = data_struct.fields.iter().map(|field| {
         let var_name = field.ident.clone().expect("tuples not supported");
 
-        field.attrs.first()
-            .and_then(|origin_struct_name| {
-                if let Type::Path(fn_type) = field.ty.clone() {
-                    Some((
-                        origin_struct_name.parse_args::<PathSegment>().ok().map(|v| v.ident)?,
-                        fn_type.path.segments.first().cloned().map(|v| v.ident)?,
-                    ))
-                } else {
-                    None
-                }
-            })
-            .map(|(origin_struct_name, fn_type)| quote!(#var_name: #fn_type::from(#origin_struct_name::get_service())))
-            .unwrap_or_else(|| quote!(#var_name: Injectable::get_service()))
+        if has_async_marker(&field.attrs) {
+            let message = format!(
+                "field `{}` is marked #[async_inject] and cannot be built by `default()`; use `default_async()` instead",
+                var_name
+            );
+            return quote!(#var_name: compile_error!(#message));
+        }
+
+        let injection_expr = resolve_injection_expr(&field.attrs, &field.ty, false);
+
+        quote!(#var_name: #injection_expr)
+    });
+
+    let try_constructor = data_struct.fields.iter().map(|field| {
+        let var_name = field.ident.clone().expect("tuples not supported");
+
+        if has_async_marker(&field.attrs) {
+            let message = format!(
+                "field `{}` is marked #[async_inject] and cannot be built by `try_default()`; use `default_async()` instead",
+                var_name
+            );
+            return quote!(#var_name: compile_error!(#message));
+        }
+
+        let injection_expr = resolve_injection_expr(&field.attrs, &field.ty, true);
+
+        quote!(#var_name: #injection_expr)
+    });
+
+    let async_constructor = data_struct.fields.iter().map(|field| {
+        let var_name = field.ident.clone().expect("tuples not supported");
+        let injection_expr = if has_async_marker(&field.attrs) {
+            resolve_async_injection_expr(&field.attrs, &field.ty)
+        } else {
+            resolve_injection_expr(&field.attrs, &field.ty, false)
+        };
+
+        quote!(#var_name: #injection_expr)
     });
 
     let out = quote! {
@@ -66,6 +91,23 @@ pub fn derive_container_fn(input: TokenStream) -> TokenStream {
             #(
                 #getters
             )*
+
+            pub fn try_default() -> Result<Self, InjectError> {
+                let mut history: Vec<&'static str> = Vec::new();
+                Ok(Self {
+                    #(
+                        #try_constructor
+                    ),*
+                })
+            }
+
+            pub async fn default_async() -> Self {
+                Self {
+                    #(
+                        #async_constructor
+                    ),*
+                }
+            }
         }
 
         impl #impl_generics Default for #name #ty_generics #where_clause {
@@ -73,7 +115,7 @@ pub fn derive_container_fn(input: TokenStream) -> TokenStream {
                 Self {
                     #(
                         #new_constructor
-                    )*
+                    ),*
                 }
             }
         }
@@ -82,22 +124,289 @@ pub fn derive_container_fn(input: TokenStream) -> TokenStream {
     out.into()
 }
 
+/// The parsed contents of an `#[inject(Origin)]` / `#[inject(Origin as provider)]` attribute.
+struct InjectOrigin {
+    ident: syn::Ident,
+    provider: Option<syn::Ident>,
+}
+
+impl Parse for InjectOrigin {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<PathSegment>()?.ident;
+        let provider = if input.peek(Token![as]) {
+            input.parse::<Token![as]>()?;
+            Some(input.parse::<syn::Ident>()?)
+        } else {
+            None
+        };
+
+        Ok(InjectOrigin { ident, provider })
+    }
+}
+
+/// How a single `Container` field (or constructor argument) should be built.
+enum FieldInjection {
+    /// `#[no_inject]`: skip `Injectable` entirely and use `Default::default()`.
+    NoInject,
+    /// `#[default(expr)]`: use the user-supplied expression verbatim.
+    Literal(proc_macro2::TokenStream),
+    /// `#[inject(Origin)]` / `#[inject(Origin as provider)]`.
+    Inject(InjectOrigin),
+    /// No field attribute: build the field's own type through `Injectable`.
+    Implicit,
+}
+
+fn classify_field_attrs(attrs: &[syn::Attribute]) -> FieldInjection {
+    for attr in attrs {
+        if attr.path.is_ident("no_inject") {
+            return FieldInjection::NoInject;
+        }
+        if attr.path.is_ident("default") {
+            if let Some(literal) = attr
+                .parse_args::<Expr>()
+                .ok()
+                .and_then(expr_to_token_stream)
+            {
+                return FieldInjection::Literal(literal);
+            }
+        }
+        if attr.path.is_ident("inject") {
+            if let Ok(origin) = attr.parse_args::<InjectOrigin>() {
+                return FieldInjection::Inject(origin);
+            }
+        }
+    }
+
+    FieldInjection::Implicit
+}
+
+/// Resolves the expression used to build a field (or constructor argument) of the given type,
+/// honouring `#[no_inject]`, `#[default(expr)]` and `#[inject(Origin)]` / `#[inject(Origin as
+/// provider)]` field attributes. When `tracked` is set, plain and `#[inject(Origin)]` fields
+/// resolve through `Injectable::get_service_tracked`, threading a local `history` vector so cycles
+/// surface as `InjectError` instead of overflowing the stack; singleton, named-provider,
+/// `#[no_inject]` and `#[default(expr)]` fields are built the same way as without tracking.
+fn resolve_injection_expr(attrs: &[syn::Attribute], ty: &Type, tracked: bool) -> proc_macro2::TokenStream {
+    let fn_type = match ty {
+        Type::Path(fn_type) => fn_type.path.segments.first().cloned().map(|v| v.ident),
+        _ => None,
+    };
+
+    match (classify_field_attrs(attrs), fn_type) {
+        (FieldInjection::NoInject, _) => quote!(Default::default()),
+        (FieldInjection::Literal(literal), _) => literal,
+        (FieldInjection::Inject(InjectOrigin { ident: _, provider: Some(provider) }), Some(fn_type))
+            if fn_type.to_string() == "Arc" =>
+        {
+            let message = format!(
+                "named providers are not supported for singleton (`Arc<_>`) fields; remove `as {}` from #[inject(...)]",
+                provider
+            );
+            quote!(compile_error!(#message))
+        }
+        (FieldInjection::Inject(InjectOrigin { ident: origin_ident, provider: None }), Some(fn_type))
+            if fn_type.to_string() == "Arc" =>
+        {
+            quote!(std::sync::Arc::clone(&#origin_ident::get_singleton()))
+        }
+        (FieldInjection::Inject(InjectOrigin { ident: origin_ident, provider: Some(provider) }), Some(fn_type)) => {
+            let provider_fn = format_ident!("get_service_{}", provider);
+            quote!(#fn_type::from(#origin_ident::#provider_fn()))
+        }
+        (FieldInjection::Inject(InjectOrigin { ident: origin_ident, provider: None }), Some(fn_type)) if tracked => {
+            quote!(#fn_type::from(#origin_ident::get_service_tracked(&mut history)?))
+        }
+        (FieldInjection::Inject(InjectOrigin { ident: origin_ident, provider: None }), Some(fn_type)) => {
+            quote!(#fn_type::from(#origin_ident::get_service()))
+        }
+        (_, _) if tracked => quote!(Injectable::get_service_tracked(&mut history)?),
+        (_, _) => quote!(Injectable::get_service()),
+    }
+}
+
+/// Whether a `Container` field is marked `#[async_inject]`, i.e. should be built through
+/// `default_async` by `.await`-ing its constructor instead of taking the synchronous path.
+fn has_async_marker(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path.is_ident("async_inject"))
+}
+
+/// Same as `resolve_injection_expr`, but for fields marked `#[async_inject]`: resolves through
+/// `AsyncInjectable::get_service_async().await` instead of the synchronous `Injectable` path.
+fn resolve_async_injection_expr(attrs: &[syn::Attribute], ty: &Type) -> proc_macro2::TokenStream {
+    let fn_type = match ty {
+        Type::Path(fn_type) => fn_type.path.segments.first().cloned().map(|v| v.ident),
+        _ => None,
+    };
+
+    match (classify_field_attrs(attrs), fn_type) {
+        (FieldInjection::NoInject, _) => quote!(Default::default()),
+        (FieldInjection::Literal(literal), _) => literal,
+        (FieldInjection::Inject(InjectOrigin { ident: origin_ident, .. }), Some(fn_type)) => {
+            quote!(#fn_type::from(#origin_ident::get_service_async().await))
+        }
+        (_, _) => quote!(AsyncInjectable::get_service_async().await),
+    }
+}
+
+/// Finds the impl item method with the given name, e.g. the `new` constructor.
+fn find_impl_method_by_name<'a>(
+    item_impl: &'a ItemImpl,
+    name: &str,
+) -> Option<&'a syn::ImplItemMethod> {
+    item_impl.items.iter().find_map(|item| match item {
+        syn::ImplItem::Method(method) if method.sig.ident == name => Some(method),
+        _ => None,
+    })
+}
+
+/// Implements `Injectable` for `#[injectable] impl Foo { fn new(...) -> Self { ... } }` by
+/// resolving every argument of the `new` constructor, so `get_service()` becomes
+/// `Foo::new(<resolved a>, <resolved b>, ...)`. `get_service_tracked` is overridden the same way,
+/// resolving every argument through the tracked path so a cycle built purely from
+/// constructor-injected types is still caught instead of overflowing the stack.
+fn injectable_from_impl(mut item_impl: ItemImpl) -> proc_macro2::TokenStream {
+    let self_ty = item_impl.self_ty.clone();
+    let (impl_generics, _, where_clause) = item_impl.generics.split_for_impl();
+
+    let method = match find_impl_method_by_name(&item_impl, "new") {
+        Some(method) => method.clone(),
+        None => {
+            return syn::Error::new_spanned(
+                &item_impl,
+                "#[injectable] on an impl block requires a `new` method",
+            )
+            .to_compile_error();
+        }
+    };
+
+    let ctor_args = method
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_type) => Some(resolve_injection_expr(&pat_type.attrs, &pat_type.ty, false)),
+            FnArg::Receiver(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    let tracked_ctor_args = method
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_type) => Some(resolve_injection_expr(&pat_type.attrs, &pat_type.ty, true)),
+            FnArg::Receiver(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(syn::ImplItem::Method(method)) = item_impl
+        .items
+        .iter_mut()
+        .find(|item| matches!(item, syn::ImplItem::Method(method) if method.sig.ident == "new"))
+    {
+        for arg in method.sig.inputs.iter_mut() {
+            if let FnArg::Typed(pat_type) = arg {
+                pat_type.attrs.retain(|attr| !attr.path.is_ident("inject"));
+            }
+        }
+    }
+
+    quote! {
+        #item_impl
+
+        impl #impl_generics Injectable for #self_ty #where_clause {
+            fn get_service() -> Self {
+                Self::new(#(#ctor_args),*)
+            }
+
+            fn get_service_tracked(history: &mut Vec<&'static str>) -> Result<Self, InjectError> {
+                let type_name = std::any::type_name::<Self>();
+
+                if history.contains(&type_name) {
+                    let mut chain = history.clone();
+                    chain.push(type_name);
+                    return Err(InjectError::Circular { chain });
+                }
+
+                let mut history = history.clone();
+                history.push(type_name);
+
+                Ok(Self::new(#(#tracked_ctor_args),*))
+            }
+        }
+    }
+}
+
 /// Auto implementation of Injectable trait
 #[proc_macro_attribute]
 pub fn injectable(args: TokenStream, input: TokenStream) -> TokenStream {
+    if let Ok(item_impl) = syn::parse::<ItemImpl>(input.clone()) {
+        return injectable_from_impl(item_impl).into();
+    }
+
     let input = parse_macro_input!(input as DeriveInput);
     let input_name = &input.ident;
 
     let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
 
-    let InjectableArgsStruct { factory } = parse_macro_input!(args as InjectableArgsStruct);
+    let InjectableArgsStruct { factory, singleton, name, is_async } =
+        parse_macro_input!(args as InjectableArgsStruct);
 
-    let out = quote! {
-        #input
+    let active_modes = [name.is_some(), singleton, is_async]
+        .iter()
+        .filter(|active| **active)
+        .count();
 
-        impl #impl_generics Injectable for #input_name #ty_generics #where_clause {
-            fn get_service() -> Self {
-                #factory
+    if active_modes > 1 {
+        return syn::Error::new_spanned(
+            input_name,
+            "`#[injectable]` accepts at most one of `name`, `singleton`, or `is_async`",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let out = if let Some(name) = name {
+        let provider_fn = format_ident!("get_service_{}", name);
+        quote! {
+            #input
+
+            impl #impl_generics #input_name #ty_generics #where_clause {
+                pub fn #provider_fn() -> Self {
+                    #factory
+                }
+            }
+        }
+    } else if is_async {
+        quote! {
+            #input
+
+            #[async_trait::async_trait]
+            impl #impl_generics AsyncInjectable for #input_name #ty_generics #where_clause {
+                async fn get_service_async() -> Self {
+                    #factory
+                }
+            }
+        }
+    } else if singleton {
+        quote! {
+            #input
+
+            impl #impl_generics SingletonInjectable for #input_name #ty_generics #where_clause {
+                fn get_singleton() -> std::sync::Arc<Self> {
+                    static INSTANCE: once_cell::sync::OnceCell<std::sync::Arc<#input_name>> = once_cell::sync::OnceCell::new();
+                    std::sync::Arc::clone(INSTANCE.get_or_init(|| std::sync::Arc::new(#factory)))
+                }
+            }
+        }
+    } else {
+        quote! {
+            #input
+
+            impl #impl_generics Injectable for #input_name #ty_generics #where_clause {
+                fn get_service() -> Self {
+                    #factory
+                }
             }
         }
     };
@@ -109,6 +418,9 @@ type InjectableArgs = Punctuated<Punctuated<Expr, Token![=>]>, Token![,]>;
 
 struct InjectableArgsStruct {
     factory: proc_macro2::TokenStream,
+    singleton: bool,
+    name: Option<String>,
+    is_async: bool,
 }
 
 impl Parse for InjectableArgsStruct {
@@ -132,7 +444,57 @@ impl Parse for InjectableArgsStruct {
                 }
             });
 
-        Ok(InjectableArgsStruct { factory })
+        let singleton = args.iter().any(|arg| {
+            arg.len() == 1
+                && arg
+                    .first()
+                    .cloned()
+                    .and_then(expr_to_token_stream)
+                    .filter(|key| key.to_string() == "singleton")
+                    .is_some()
+        });
+
+        let name = args.iter().find_map(|arg| {
+            if arg.len() != 1 {
+                return None;
+            }
+            match arg.first()? {
+                Expr::Assign(assign) if matches!(&*assign.left, Expr::Path(p) if p.path.is_ident("name")) => {
+                    match &*assign.right {
+                        Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(value), .. }) => Some(value.value()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        });
+
+        let is_async = args.iter().any(|arg| {
+            arg.len() == 1
+                && arg
+                    .first()
+                    .cloned()
+                    .and_then(expr_to_token_stream)
+                    .filter(|key| key.to_string() == "is_async")
+                    .is_some()
+        });
+
+        // `expr_to_token_stream` rejects `.await` expressions, so an async factory such as
+        // `async { .. }.await` is taken verbatim instead of falling back to `Default::default()`.
+        let factory = args
+            .iter()
+            .filter(|_| is_async)
+            .find_map(|arg| match (arg.first(), arg.last()) {
+                (Some(Expr::Path(key)), Some(Expr::Await(awaited)))
+                    if arg.len() == 2 && key.path.is_ident("factory") =>
+                {
+                    Some(quote!(#awaited))
+                }
+                _ => None,
+            })
+            .unwrap_or(factory);
+
+        Ok(InjectableArgsStruct { factory, singleton, name, is_async })
     }
 }
 